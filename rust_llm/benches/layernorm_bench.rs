@@ -0,0 +1,134 @@
+// Benchmarks comparing the serial layernorm passes against their rayon
+// data-parallel counterparts on a representative GPT-2-sized workload.
+// Run with: cargo bench --features rayon --bench layernorm_bench
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_llm::llm::train_gpt2::{
+    layernorm_backward, layernorm_backward_parallel, layernorm_forward,
+    layernorm_forward_parallel,
+};
+
+const BATCH_SIZE: usize = 32;
+const SEQUENCE_LENGTH: usize = 1024;
+const CHANNELS: usize = 768;
+const EPS: f32 = 1e-5;
+
+fn make_inputs() -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+    let rows = BATCH_SIZE * SEQUENCE_LENGTH;
+    let inp: Vec<f32> = (0..rows * CHANNELS)
+        .map(|i| (i % 997) as f32 / 997.0)
+        .collect();
+    let weights: Vec<f32> = (0..CHANNELS).map(|i| 1.0 + (i % 13) as f32 / 13.0).collect();
+    let biases: Vec<f32> = (0..CHANNELS).map(|i| (i % 7) as f32 / 7.0).collect();
+    (inp, weights, biases)
+}
+
+fn bench_forward(c: &mut Criterion) {
+    let (inp, weights, biases) = make_inputs();
+    let rows = BATCH_SIZE * SEQUENCE_LENGTH;
+    let mut out = vec![0.0f32; rows * CHANNELS];
+    let mut mean_array = vec![0.0f32; rows];
+    let mut rstd_array = vec![0.0f32; rows];
+
+    c.bench_function("layernorm_forward (serial)", |b| {
+        b.iter(|| {
+            layernorm_forward(
+                black_box(&mut out),
+                black_box(&mut mean_array),
+                black_box(&mut rstd_array),
+                black_box(&inp),
+                black_box(&weights),
+                black_box(&biases),
+                BATCH_SIZE,
+                SEQUENCE_LENGTH,
+                CHANNELS,
+                EPS,
+                false,
+            )
+        })
+    });
+
+    c.bench_function("layernorm_forward_parallel (rayon)", |b| {
+        b.iter(|| {
+            layernorm_forward_parallel(
+                black_box(&mut out),
+                black_box(&mut mean_array),
+                black_box(&mut rstd_array),
+                black_box(&inp),
+                black_box(&weights),
+                black_box(&biases),
+                BATCH_SIZE,
+                SEQUENCE_LENGTH,
+                CHANNELS,
+                EPS,
+                false,
+            )
+        })
+    });
+}
+
+fn bench_backward(c: &mut Criterion) {
+    let (inp, weights, _biases) = make_inputs();
+    let rows = BATCH_SIZE * SEQUENCE_LENGTH;
+    let mut mean_array = vec![0.0f32; rows];
+    let mut rstd_array = vec![0.0f32; rows];
+    let mut out = vec![0.0f32; rows * CHANNELS];
+    layernorm_forward(
+        &mut out,
+        &mut mean_array,
+        &mut rstd_array,
+        &inp,
+        &weights,
+        &_biases,
+        BATCH_SIZE,
+        SEQUENCE_LENGTH,
+        CHANNELS,
+        EPS,
+        false,
+    );
+    let dout = out;
+
+    c.bench_function("layernorm_backward (serial)", |b| {
+        b.iter(|| {
+            let mut dinp = vec![0.0f32; rows * CHANNELS];
+            let mut dweight = vec![0.0f32; CHANNELS];
+            let mut dbias = vec![0.0f32; CHANNELS];
+            layernorm_backward(
+                black_box(&mut dinp),
+                black_box(&mut dweight),
+                black_box(&mut dbias),
+                black_box(&mut dout.clone()),
+                black_box(&inp),
+                black_box(&weights),
+                black_box(&mean_array),
+                black_box(&rstd_array),
+                BATCH_SIZE,
+                SEQUENCE_LENGTH,
+                CHANNELS,
+            )
+        })
+    });
+
+    c.bench_function("layernorm_backward_parallel (rayon)", |b| {
+        b.iter(|| {
+            let mut dinp = vec![0.0f32; rows * CHANNELS];
+            let mut dweight = vec![0.0f32; CHANNELS];
+            let mut dbias = vec![0.0f32; CHANNELS];
+            layernorm_backward_parallel(
+                black_box(&mut dinp),
+                black_box(&mut dweight),
+                black_box(&mut dbias),
+                black_box(&dout),
+                black_box(&inp),
+                black_box(&weights),
+                black_box(&mean_array),
+                black_box(&rstd_array),
+                BATCH_SIZE,
+                SEQUENCE_LENGTH,
+                CHANNELS,
+            )
+        })
+    });
+}
+
+criterion_group!(benches, bench_forward, bench_backward);
+criterion_main!(benches);
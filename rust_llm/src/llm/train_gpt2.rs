@@ -9,6 +9,9 @@
 // of activations gets normalized, then scaled and shifted
 const EPS: f32 = 1e-5;
 
+// eps and unbiased let callers match PyTorch's std_mean/var_mean semantics;
+// pass eps=EPS, unbiased=false to reproduce the previous fixed behavior
+#[allow(clippy::too_many_arguments)]
 pub fn layernorm_forward(
     out: &mut [f32],
     mean_array: &mut [f32],
@@ -19,22 +22,33 @@ pub fn layernorm_forward(
     batch_size: usize,
     sequence_length: usize,
     channels: usize,
+    eps: f32,
+    unbiased: bool,
 ) {
     for b_idx in 0..batch_size {
         for t_idx in 0..sequence_length {
             // Seek to the input position inp[b,t,:]
             let x_offset_start = b_idx * sequence_length * channels + t_idx * channels;
             let x_offset_end = x_offset_start + channels;
-            // calculate mean of layer
-            let mean = inp[x_offset_start..x_offset_end].iter().sum::<f32>() / channels as f32;
-            // calculate variance of layer
-            let variance = inp[x_offset_start..x_offset_end]
-                .iter()
-                .fold(0.0, |acc, x| acc + (x - mean).powi(2))
-                / channels as f32;
+
+            // single-pass mean/variance via Welford's online algorithm
+            let mut count = 0.0_f32;
+            let mut mean = 0.0_f32;
+            let mut m2 = 0.0_f32;
+            for &x in &inp[x_offset_start..x_offset_end] {
+                count += 1.0;
+                let delta = x - mean;
+                mean += delta / count;
+                m2 += delta * (x - mean);
+            }
+            let variance = if unbiased && count > 1.0 {
+                m2 / (count - 1.0)
+            } else {
+                m2 / count
+            };
 
             // Calculate rstd (reciprocal standard deviation)
-            let rstd = (variance + EPS).powf(-0.5);
+            let rstd = (variance + eps).powf(-0.5);
 
             // Output offset is same as input offset.
             // TODO: Rewrite this to be immutable
@@ -102,3 +116,633 @@ pub fn layernorm_backward(
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// forward-mode (JVP) companion to layernorm_forward: given the primal inputs
+// plus the mean/rstd saved by the forward pass, and tangents for inp/weights/
+// biases, computes the output tangent dout_t (a directional derivative).
+// reference: the forward-AD formulas PyTorch added for layer/batch/group norm
+pub fn layernorm_jvp(
+    dout_t: &mut [f32],
+    inp: &[f32],
+    weights: &[f32],
+    _biases: &[f32],
+    mean_array: &[f32],
+    rstd_array: &[f32],
+    dinp_t: &[f32],
+    dweight_t: &[f32],
+    dbias_t: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+) {
+    for b_idx in 0..batch_size {
+        for t_idx in 0..sequence_length {
+            let x_offset_start = b_idx * sequence_length * channels + t_idx * channels;
+            let x_offset_end = x_offset_start + channels;
+            let mean = mean_array[b_idx * sequence_length + t_idx];
+            let rstd = rstd_array[b_idx * sequence_length + t_idx];
+
+            // mean tangent: mean of the input tangent over channels
+            let mean_t =
+                dinp_t[x_offset_start..x_offset_end].iter().sum::<f32>() / channels as f32;
+            // variance tangent simplifies because sum(x_i - mean) == 0
+            let var_t = 2.0
+                * inp[x_offset_start..x_offset_end]
+                    .iter()
+                    .zip(&dinp_t[x_offset_start..x_offset_end])
+                    .fold(0.0, |acc, (x, x_t)| acc + (x - mean) * x_t)
+                / channels as f32;
+            let rstd_t = -0.5 * rstd.powi(3) * var_t;
+
+            for i in 0..channels {
+                let x_offset = x_offset_start + i;
+                let norm = (inp[x_offset] - mean) * rstd;
+                let norm_t = (dinp_t[x_offset] - mean_t) * rstd + (inp[x_offset] - mean) * rstd_t;
+                dout_t[x_offset] = norm_t * weights[i] + norm * dweight_t[i] + dbias_t[i];
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// reference: https://pytorch.org/docs/stable/generated/torch.nn.BatchNorm1d.html
+// unlike layernorm, batchnorm normalizes each channel independently across
+// the batch*time axis, and tracks running statistics for use at eval time.
+// saved_mean/saved_rstd and running_mean/running_var are (C,) buffers
+#[allow(clippy::too_many_arguments)]
+pub fn batchnorm_forward(
+    out: &mut [f32],
+    saved_mean: &mut [f32],
+    saved_rstd: &mut [f32],
+    running_mean: &mut [f32],
+    running_var: &mut [f32],
+    inp: &[f32],
+    weights: &[f32],
+    biases: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+    momentum: f32,
+    training: bool,
+) {
+    let n = (batch_size * sequence_length) as f32;
+    for c in 0..channels {
+        let (mean, rstd) = if training {
+            let mut mean = 0.0;
+            for b_idx in 0..batch_size {
+                for t_idx in 0..sequence_length {
+                    mean += inp[(b_idx * sequence_length + t_idx) * channels + c];
+                }
+            }
+            mean /= n;
+
+            let mut variance = 0.0;
+            for b_idx in 0..batch_size {
+                for t_idx in 0..sequence_length {
+                    let x = inp[(b_idx * sequence_length + t_idx) * channels + c];
+                    variance += (x - mean).powi(2);
+                }
+            }
+            variance /= n;
+
+            // update running stats by linear interpolation, using the
+            // unbiased estimator for the running variance (as PyTorch does)
+            running_mean[c] = momentum * running_mean[c] + (1.0 - momentum) * mean;
+            let unbiased_variance = if n > 1.0 {
+                variance * n / (n - 1.0)
+            } else {
+                variance
+            };
+            running_var[c] = momentum * running_var[c] + (1.0 - momentum) * unbiased_variance;
+
+            let rstd = (variance + EPS).powf(-0.5);
+            saved_mean[c] = mean;
+            saved_rstd[c] = rstd;
+            (mean, rstd)
+        } else {
+            (running_mean[c], (running_var[c] + EPS).powf(-0.5))
+        };
+
+        for b_idx in 0..batch_size {
+            for t_idx in 0..sequence_length {
+                let idx = (b_idx * sequence_length + t_idx) * channels + c;
+                let normalized = (inp[idx] - mean) * rstd;
+                out[idx] = normalized * weights[c] + biases[c];
+            }
+        }
+    }
+}
+
+pub fn batchnorm_backward(
+    dinp: &mut [f32],
+    dweight: &mut [f32],
+    dbias: &mut [f32],
+    dout: &[f32],
+    inp: &[f32],
+    weights: &[f32],
+    saved_mean: &[f32],
+    saved_rstd: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+) {
+    let n = (batch_size * sequence_length) as f32;
+    for c in 0..channels {
+        let mean_c = saved_mean[c];
+        let rstd_c = saved_rstd[c];
+
+        // first: two reduce operations across the batch*time axis
+        let mut dnorm_mean: f32 = 0.0;
+        let mut dnorm_norm_mean: f32 = 0.0;
+        for b_idx in 0..batch_size {
+            for t_idx in 0..sequence_length {
+                let idx = (b_idx * sequence_length + t_idx) * channels + c;
+                let norm = (inp[idx] - mean_c) * rstd_c;
+                let dnorm = weights[c] * dout[idx];
+                dnorm_mean += dnorm;
+                dnorm_norm_mean += dnorm * norm;
+            }
+        }
+        dnorm_mean /= n;
+        dnorm_norm_mean /= n;
+
+        // now iterate again and accumulate all the gradients
+        for b_idx in 0..batch_size {
+            for t_idx in 0..sequence_length {
+                let idx = (b_idx * sequence_length + t_idx) * channels + c;
+                let norm = (inp[idx] - mean_c) * rstd_c;
+                let dnorm = weights[c] * dout[idx];
+
+                dbias[c] += dout[idx];
+                dweight[c] += norm * dout[idx];
+
+                let mut dval = dnorm;
+                dval -= dnorm_mean;
+                dval -= norm * dnorm_norm_mean;
+                dval *= rstd_c;
+                dinp[idx] += dval;
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// reference: https://pytorch.org/docs/stable/generated/torch.nn.GroupNorm.html
+// generalizes layernorm_forward: channels are split into num_groups
+// contiguous groups, and mean/rstd are computed independently within each
+// group at each (b,t) position, while weight/bias stay per-channel (length C).
+// with num_groups == 1 this is identical to layernorm_forward.
+// mean and rstd are (B,T,G) buffers, to be used later in backward pass
+#[allow(clippy::too_many_arguments)]
+pub fn groupnorm_forward(
+    out: &mut [f32],
+    mean_array: &mut [f32],
+    rstd_array: &mut [f32],
+    inp: &[f32],
+    weights: &[f32],
+    biases: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+    num_groups: usize,
+) {
+    debug_assert!(
+        channels % num_groups == 0,
+        "channels ({channels}) must be evenly divisible by num_groups ({num_groups})"
+    );
+    let group_size = channels / num_groups;
+    for b_idx in 0..batch_size {
+        for t_idx in 0..sequence_length {
+            let row_offset = b_idx * sequence_length * channels + t_idx * channels;
+            for g_idx in 0..num_groups {
+                let g_offset_start = row_offset + g_idx * group_size;
+                let g_offset_end = g_offset_start + group_size;
+
+                // single-pass mean/variance via Welford's online algorithm,
+                // matching layernorm_forward (G=1 reduces to the same sums)
+                let mut count = 0.0_f32;
+                let mut mean = 0.0_f32;
+                let mut m2 = 0.0_f32;
+                for &x in &inp[g_offset_start..g_offset_end] {
+                    count += 1.0;
+                    let delta = x - mean;
+                    mean += delta / count;
+                    m2 += delta * (x - mean);
+                }
+                let variance = m2 / count;
+                let rstd = (variance + EPS).powf(-0.5);
+
+                for i in 0..group_size {
+                    let c = g_idx * group_size + i;
+                    let x_offset = row_offset + c;
+                    let normalized = (inp[x_offset] - mean) * rstd;
+                    out[x_offset] = normalized * weights[c] + biases[c];
+                }
+
+                let group_index = (b_idx * sequence_length + t_idx) * num_groups + g_idx;
+                mean_array[group_index] = mean;
+                rstd_array[group_index] = rstd;
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn groupnorm_backward(
+    dinp: &mut [f32],
+    dweight: &mut [f32],
+    dbias: &mut [f32],
+    dout: &[f32],
+    input: &[f32],
+    weights: &[f32],
+    means: &[f32],
+    rstds: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+    num_groups: usize,
+) {
+    debug_assert!(
+        channels % num_groups == 0,
+        "channels ({channels}) must be evenly divisible by num_groups ({num_groups})"
+    );
+    let group_size = channels / num_groups;
+    for b_idx in 0..batch_size {
+        for t_idx in 0..sequence_length {
+            let row_offset = b_idx * sequence_length * channels + t_idx * channels;
+            for g_idx in 0..num_groups {
+                let group_index = (b_idx * sequence_length + t_idx) * num_groups + g_idx;
+                let mean_g = means[group_index];
+                let rstd_g = rstds[group_index];
+                let g_offset_start = row_offset + g_idx * group_size;
+
+                // first: two reduce operations, scoped to this group
+                let mut dnorm_mean: f32 = 0.0;
+                let mut dnorm_norm_mean: f32 = 0.0;
+                for i in 0..group_size {
+                    let c = g_idx * group_size + i;
+                    let x_offset = g_offset_start + i;
+                    let norm_bti = (input[x_offset] - mean_g) * rstd_g;
+                    let dnorm_i = weights[c] * dout[x_offset];
+                    dnorm_mean += dnorm_i;
+                    dnorm_norm_mean += dnorm_i * norm_bti;
+                }
+                dnorm_mean /= group_size as f32;
+                dnorm_norm_mean /= group_size as f32;
+
+                // now iterate again and accumulate all the gradients
+                for i in 0..group_size {
+                    let c = g_idx * group_size + i;
+                    let x_offset = g_offset_start + i;
+                    let norm_bti = (input[x_offset] - mean_g) * rstd_g;
+                    let dnorm_i = weights[c] * dout[x_offset];
+
+                    dbias[c] += dout[x_offset];
+                    dweight[c] += norm_bti * dout[x_offset];
+
+                    let mut dval: f32 = 0.0;
+                    dval += dnorm_i;
+                    dval -= dnorm_mean;
+                    dval -= norm_bti * dnorm_norm_mean;
+                    dval *= rstd_g;
+                    dinp[x_offset] += dval;
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// data-parallel variants of the layernorm passes, gated behind the "rayon"
+// feature. Every (b,t) row is independent in the forward pass, so rows are
+// split across threads directly. In the backward pass dinp is written
+// per-row without contention, while dweight/dbias are accumulated into
+// thread-local buffers and reduced at the end.
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+#[allow(clippy::too_many_arguments)]
+pub fn layernorm_forward_parallel(
+    out: &mut [f32],
+    mean_array: &mut [f32],
+    rstd_array: &mut [f32],
+    inp: &[f32],
+    weights: &[f32],
+    biases: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+    eps: f32,
+    unbiased: bool,
+) {
+    let rows = batch_size * sequence_length;
+    out[..rows * channels]
+        .par_chunks_mut(channels)
+        .zip(mean_array[..rows].par_iter_mut())
+        .zip(rstd_array[..rows].par_iter_mut())
+        .zip(inp[..rows * channels].par_chunks(channels))
+        .for_each(|(((out_row, mean_slot), rstd_slot), inp_row)| {
+            let mut count = 0.0_f32;
+            let mut mean = 0.0_f32;
+            let mut m2 = 0.0_f32;
+            for &x in inp_row {
+                count += 1.0;
+                let delta = x - mean;
+                mean += delta / count;
+                m2 += delta * (x - mean);
+            }
+            let variance = if unbiased && count > 1.0 {
+                m2 / (count - 1.0)
+            } else {
+                m2 / count
+            };
+            let rstd = (variance + eps).powf(-0.5);
+
+            for i in 0..channels {
+                let normalized = (inp_row[i] - mean) * rstd;
+                out_row[i] = normalized * weights[i] + biases[i];
+            }
+            *mean_slot = mean;
+            *rstd_slot = rstd;
+        });
+}
+
+#[cfg(feature = "rayon")]
+#[allow(clippy::too_many_arguments)]
+pub fn layernorm_backward_parallel(
+    dinp: &mut [f32],
+    dweight: &mut [f32],
+    dbias: &mut [f32],
+    dout: &[f32],
+    input: &[f32],
+    weights: &[f32],
+    means: &[f32],
+    rstds: &[f32],
+    batch_size: usize,
+    sequence_length: usize,
+    channels: usize,
+) {
+    let rows = batch_size * sequence_length;
+    let (dweight_total, dbias_total) = dinp[..rows * channels]
+        .par_chunks_mut(channels)
+        .zip(dout[..rows * channels].par_chunks(channels))
+        .zip(input[..rows * channels].par_chunks(channels))
+        .zip(means[..rows].par_iter())
+        .zip(rstds[..rows].par_iter())
+        .fold(
+            || (vec![0.0f32; channels], vec![0.0f32; channels]),
+            |(mut dweight_local, mut dbias_local),
+             ((((dinp_row, dout_row), input_row), &mean_bt), &rstd_bt)| {
+                // first: two reduce operations
+                let mut dnorm_mean: f32 = 0.0;
+                let mut dnorm_norm_mean: f32 = 0.0;
+                for i in 0..channels {
+                    let norm_bti = (input_row[i] - mean_bt) * rstd_bt;
+                    let dnorm_i = weights[i] * dout_row[i];
+                    dnorm_mean += dnorm_i;
+                    dnorm_norm_mean += dnorm_i * norm_bti;
+                }
+                dnorm_mean /= channels as f32;
+                dnorm_norm_mean /= channels as f32;
+
+                // now iterate again and accumulate all the gradients
+                for i in 0..channels {
+                    let norm_bti = (input_row[i] - mean_bt) * rstd_bt;
+                    let dnorm_i = weights[i] * dout_row[i];
+                    dbias_local[i] += dout_row[i];
+                    dweight_local[i] += norm_bti * dout_row[i];
+
+                    let mut dval: f32 = 0.0;
+                    dval += dnorm_i;
+                    dval -= dnorm_mean;
+                    dval -= norm_bti * dnorm_norm_mean;
+                    dval *= rstd_bt;
+                    dinp_row[i] += dval;
+                }
+                (dweight_local, dbias_local)
+            },
+        )
+        .reduce(
+            || (vec![0.0f32; channels], vec![0.0f32; channels]),
+            |(mut dw_a, mut db_a), (dw_b, db_b)| {
+                for i in 0..channels {
+                    dw_a[i] += dw_b[i];
+                    db_a[i] += db_b[i];
+                }
+                (dw_a, db_a)
+            },
+        );
+
+    for i in 0..channels {
+        dweight[i] += dweight_total[i];
+        dbias[i] += dbias_total[i];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layernorm_jvp_matches_finite_difference_of_forward() {
+        let batch_size = 2;
+        let sequence_length = 3;
+        let channels = 4;
+        let rows = batch_size * sequence_length;
+
+        // deterministic pseudo-random inputs, no external rng dependency
+        let inp: Vec<f32> = (0..rows * channels).map(|i| (i as f32 * 0.37).sin()).collect();
+        let weights: Vec<f32> = (0..channels).map(|i| 1.0 + i as f32 * 0.1).collect();
+        let biases: Vec<f32> = (0..channels).map(|i| i as f32 * 0.05).collect();
+        let dinp_t: Vec<f32> = (0..rows * channels).map(|i| (i as f32 * 0.71).cos()).collect();
+        let dweight_t: Vec<f32> = (0..channels).map(|i| (i as f32 * 0.13).cos()).collect();
+        let dbias_t: Vec<f32> = (0..channels).map(|i| (i as f32 * 0.19).sin()).collect();
+
+        let mut mean_array = vec![0.0; rows];
+        let mut rstd_array = vec![0.0; rows];
+        let mut out = vec![0.0; rows * channels];
+        layernorm_forward(
+            &mut out,
+            &mut mean_array,
+            &mut rstd_array,
+            &inp,
+            &weights,
+            &biases,
+            batch_size,
+            sequence_length,
+            channels,
+            EPS,
+            false,
+        );
+
+        let mut dout_t = vec![0.0; rows * channels];
+        layernorm_jvp(
+            &mut dout_t,
+            &inp,
+            &weights,
+            &biases,
+            &mean_array,
+            &rstd_array,
+            &dinp_t,
+            &dweight_t,
+            &dbias_t,
+            batch_size,
+            sequence_length,
+            channels,
+        );
+
+        // central finite difference: perturb inp/weights/biases along the
+        // tangent direction by +-h and measure the change in forward output
+        let h = 1e-3;
+        let perturbed_output = |scale: f32| -> Vec<f32> {
+            let inp_p: Vec<f32> = inp.iter().zip(&dinp_t).map(|(x, xt)| x + scale * xt).collect();
+            let weights_p: Vec<f32> = weights
+                .iter()
+                .zip(&dweight_t)
+                .map(|(w, wt)| w + scale * wt)
+                .collect();
+            let biases_p: Vec<f32> = biases
+                .iter()
+                .zip(&dbias_t)
+                .map(|(bi, bt)| bi + scale * bt)
+                .collect();
+            let mut mean_p = vec![0.0; rows];
+            let mut rstd_p = vec![0.0; rows];
+            let mut out_p = vec![0.0; rows * channels];
+            layernorm_forward(
+                &mut out_p,
+                &mut mean_p,
+                &mut rstd_p,
+                &inp_p,
+                &weights_p,
+                &biases_p,
+                batch_size,
+                sequence_length,
+                channels,
+                EPS,
+                false,
+            );
+            out_p
+        };
+        let out_plus = perturbed_output(h);
+        let out_minus = perturbed_output(-h);
+
+        for i in 0..rows * channels {
+            let finite_diff = (out_plus[i] - out_minus[i]) / (2.0 * h);
+            assert!(
+                (finite_diff - dout_t[i]).abs() < 1e-2,
+                "mismatch at index {i}: finite-diff {finite_diff} vs jvp {}",
+                dout_t[i]
+            );
+        }
+    }
+
+    #[test]
+    fn layernorm_forward_welford_matches_two_pass_reference() {
+        let batch_size = 3;
+        let sequence_length = 2;
+        let channels = 16;
+        let rows = batch_size * sequence_length;
+
+        // deterministic pseudo-random inputs, no external rng dependency
+        let inp: Vec<f32> = (0..rows * channels)
+            .map(|i| ((i as f32 * 12.9898).sin() * 43758.5453).fract())
+            .collect();
+        let weights = vec![1.0; channels];
+        let biases = vec![0.0; channels];
+
+        let mut mean_array = vec![0.0; rows];
+        let mut rstd_array = vec![0.0; rows];
+        let mut out = vec![0.0; rows * channels];
+        layernorm_forward(
+            &mut out,
+            &mut mean_array,
+            &mut rstd_array,
+            &inp,
+            &weights,
+            &biases,
+            batch_size,
+            sequence_length,
+            channels,
+            EPS,
+            false,
+        );
+
+        for row in 0..rows {
+            let start = row * channels;
+            let end = start + channels;
+            let slice = &inp[start..end];
+
+            // the two-pass reference the old layernorm_forward used before
+            // the Welford refactor
+            let two_pass_mean = slice.iter().sum::<f32>() / channels as f32;
+            let two_pass_variance = slice
+                .iter()
+                .fold(0.0, |acc, x| acc + (x - two_pass_mean).powi(2))
+                / channels as f32;
+            let two_pass_rstd = (two_pass_variance + EPS).powf(-0.5);
+
+            assert!(
+                (mean_array[row] - two_pass_mean).abs() < 1e-4,
+                "mean mismatch at row {row}: welford {} vs two-pass {two_pass_mean}",
+                mean_array[row]
+            );
+            assert!(
+                (rstd_array[row] - two_pass_rstd).abs() < 1e-3,
+                "rstd mismatch at row {row}: welford {} vs two-pass {two_pass_rstd}",
+                rstd_array[row]
+            );
+        }
+    }
+
+    #[test]
+    fn groupnorm_forward_with_one_group_matches_layernorm_forward() {
+        let batch_size = 2;
+        let sequence_length = 3;
+        let channels = 8;
+        let rows = batch_size * sequence_length;
+
+        let inp: Vec<f32> = (0..rows * channels).map(|i| (i as f32 * 0.53).sin()).collect();
+        let weights: Vec<f32> = (0..channels).map(|i| 1.0 + i as f32 * 0.1).collect();
+        let biases: Vec<f32> = (0..channels).map(|i| i as f32 * 0.05).collect();
+
+        let mut ln_out = vec![0.0; rows * channels];
+        let mut ln_mean = vec![0.0; rows];
+        let mut ln_rstd = vec![0.0; rows];
+        layernorm_forward(
+            &mut ln_out,
+            &mut ln_mean,
+            &mut ln_rstd,
+            &inp,
+            &weights,
+            &biases,
+            batch_size,
+            sequence_length,
+            channels,
+            EPS,
+            false,
+        );
+
+        let mut gn_out = vec![0.0; rows * channels];
+        let mut gn_mean = vec![0.0; rows]; // G=1, so (B,T,G) has the same length as (B,T)
+        let mut gn_rstd = vec![0.0; rows];
+        groupnorm_forward(
+            &mut gn_out,
+            &mut gn_mean,
+            &mut gn_rstd,
+            &inp,
+            &weights,
+            &biases,
+            batch_size,
+            sequence_length,
+            channels,
+            1,
+        );
+
+        assert_eq!(ln_out, gn_out, "G=1 groupnorm output must be bit-identical to layernorm");
+        assert_eq!(ln_mean, gn_mean, "G=1 groupnorm mean must be bit-identical to layernorm");
+        assert_eq!(ln_rstd, gn_rstd, "G=1 groupnorm rstd must be bit-identical to layernorm");
+    }
+}